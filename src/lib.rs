@@ -0,0 +1,708 @@
+//! A small state-vector simulator: single qubits, a multi-qubit register with
+//! tensor-product state, a standard gate library, a circuit builder, and
+//! measurement utilities (multi-shot sampling, arbitrary bases, the QFT).
+
+use rand::Rng;
+use std::collections::HashMap;
+use std::f64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QRegister {
+    num_qubits: usize,
+    state: Vec<Complex>,
+}
+
+impl QRegister {
+    /// Create an `n`-qubit register initialized to |00…0⟩.
+    pub fn new(num_qubits: usize) -> Self {
+        let mut state = vec![Complex::new(0.0, 0.0); 1 << num_qubits];
+        state[0] = Complex::new(1.0, 0.0);
+        Self { num_qubits, state }
+    }
+
+    /// Build a register whose amplitudes are the Kronecker product of the
+    /// individual qubit states, so product states can be assembled from
+    /// separate qubits before entangling gates mix them.
+    pub fn from_qubits(qubits: &[Qubit]) -> Self {
+        let mut state = vec![Complex::new(1.0, 0.0)];
+        for qubit in qubits {
+            let mut next = Vec::with_capacity(state.len() * 2);
+            for amp in &state {
+                next.push(amp.mul(&qubit.state[0]));
+                next.push(amp.mul(&qubit.state[1]));
+            }
+            state = next;
+        }
+        Self { num_qubits: qubits.len(), state }
+    }
+
+    /// Bit position within a basis index for logical qubit `target`. Qubit 0
+    /// is the most significant bit, matching the `q0 ⊗ q1 ⊗ …` ordering of
+    /// [`QRegister::from_qubits`].
+    fn bit_of(&self, target: usize) -> usize {
+        self.num_qubits - 1 - target
+    }
+
+    /// Apply a single-qubit gate to `target` by updating the amplitude pairs
+    /// that differ only in the target bit, avoiding the full 2^n × 2^n operator.
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        let mask = 1usize << self.bit_of(target);
+        let mut new_state = self.state.clone();
+        for i in 0..self.state.len() {
+            if i & mask == 0 {
+                let j = i | mask;
+                let a = self.state[i];
+                let b = self.state[j];
+                new_state[i] = gate.matrix[0][0].mul(&a).add(&gate.matrix[0][1].mul(&b));
+                new_state[j] = gate.matrix[1][0].mul(&a).add(&gate.matrix[1][1].mul(&b));
+            }
+        }
+        self.state = new_state;
+    }
+
+    /// Apply `gate` to `target` only on basis states where `control` is 1,
+    /// the building block for the two-qubit entangling gates.
+    pub fn apply_controlled_gate(&mut self, gate: &QuantumGate, control: usize, target: usize) {
+        let control_mask = 1usize << self.bit_of(control);
+        let target_mask = 1usize << self.bit_of(target);
+        let mut new_state = self.state.clone();
+        for i in 0..self.state.len() {
+            if i & control_mask != 0 && i & target_mask == 0 {
+                let j = i | target_mask;
+                let a = self.state[i];
+                let b = self.state[j];
+                new_state[i] = gate.matrix[0][0].mul(&a).add(&gate.matrix[0][1].mul(&b));
+                new_state[j] = gate.matrix[1][0].mul(&a).add(&gate.matrix[1][1].mul(&b));
+            }
+        }
+        self.state = new_state;
+    }
+
+    /// Measure a single qubit in the computational basis, collapsing it while
+    /// renormalizing the amplitudes of the qubits left untouched.
+    pub fn measure(&mut self, target: usize) -> usize {
+        let mask = 1usize << self.bit_of(target);
+        let prob_one: f64 = self
+            .state
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i & mask != 0)
+            .map(|(_, amp)| amp.modulus_squared())
+            .sum();
+        let r: f64 = rand::thread_rng().gen();
+        let outcome = if r < prob_one { 1 } else { 0 };
+        let kept = if outcome == 1 { mask } else { 0 };
+        for (i, amp) in self.state.iter_mut().enumerate() {
+            if i & mask != kept {
+                *amp = Complex::new(0.0, 0.0);
+            }
+        }
+        self.renormalize();
+        outcome
+    }
+
+    /// Measure a single qubit in an arbitrary basis, rotating it onto the
+    /// computational basis before the collapse so the outcome is reported
+    /// relative to `basis`.
+    pub fn measure_in_basis(&mut self, target: usize, basis: &MeasurementBasis) -> usize {
+        self.apply_gate(&basis.change_of_basis(), target);
+        self.measure(target)
+    }
+
+    /// Measure every qubit, collapsing the register onto a single basis state
+    /// and returning that state's index.
+    pub fn measure_all(&mut self) -> usize {
+        let r: f64 = rand::thread_rng().gen();
+        let mut cumulative = 0.0;
+        let mut outcome = self.state.len() - 1;
+        for (i, amp) in self.state.iter().enumerate() {
+            cumulative += amp.modulus_squared();
+            if r < cumulative {
+                outcome = i;
+                break;
+            }
+        }
+        for (i, amp) in self.state.iter_mut().enumerate() {
+            *amp = if i == outcome {
+                Complex::new(1.0, 0.0)
+            } else {
+                Complex::new(0.0, 0.0)
+            };
+        }
+        outcome
+    }
+
+    /// Draw `shots` outcomes from the register's probability distribution
+    /// without mutating it, returning a count per observed basis state. This is
+    /// the "run the circuit N times" workflow, leaving the state intact so the
+    /// same register can be sampled repeatedly.
+    pub fn sample(&self, shots: usize) -> HashMap<usize, usize> {
+        let probabilities: Vec<f64> = self.state.iter().map(|amp| amp.modulus_squared()).collect();
+        let mut counts = HashMap::new();
+        let mut rng = rand::thread_rng();
+        for _ in 0..shots {
+            let r: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            let mut outcome = probabilities.len() - 1;
+            for (i, prob) in probabilities.iter().enumerate() {
+                cumulative += prob;
+                if r < cumulative {
+                    outcome = i;
+                    break;
+                }
+            }
+            *counts.entry(outcome).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Swap two qubits by exchanging the amplitudes of the basis states whose
+    /// `a` and `b` bits differ.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let mask_a = 1usize << self.bit_of(a);
+        let mask_b = 1usize << self.bit_of(b);
+        for i in 0..self.state.len() {
+            let bit_a = i & mask_a != 0;
+            let bit_b = i & mask_b != 0;
+            // Handle each pair once: only act when a is set and b is clear.
+            if bit_a && !bit_b {
+                let j = (i & !mask_a) | mask_b;
+                self.state.swap(i, j);
+            }
+        }
+    }
+
+    fn renormalize(&mut self) {
+        let norm: f64 = self.state.iter().map(|amp| amp.modulus_squared()).sum::<f64>().sqrt();
+        for amp in self.state.iter_mut() {
+            amp.real /= norm;
+            amp.imag /= norm;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex {
+    pub real: f64,
+    pub imag: f64,
+}
+
+impl Complex {
+    pub fn new(real: f64, imag: f64) -> Self {
+        Self { real, imag }
+    }
+
+    pub fn modulus_squared(&self) -> f64 {
+        self.real * self.real + self.imag * self.imag
+    }
+
+    pub fn add(&self, other: &Complex) -> Complex {
+        Complex::new(self.real + other.real, self.imag + other.imag)
+    }
+
+    pub fn conjugate(&self) -> Complex {
+        Complex::new(self.real, -self.imag)
+    }
+
+    pub fn mul(&self, other: &Complex) -> Complex {
+        Complex::new(
+            self.real * other.real - self.imag * other.imag,
+            self.real * other.imag + self.imag * other.real,
+        )
+    }
+}
+
+/// The basis in which a qubit is measured. `Z` is the computational basis;
+/// `X` and `Y` are the Pauli eigenbases, and `Custom` takes two orthonormal
+/// basis vectors whose labels become outcomes 0 and 1.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MeasurementBasis {
+    Z,
+    X,
+    Y,
+    Custom([Complex; 2], [Complex; 2]),
+}
+
+impl MeasurementBasis {
+    /// The unitary that rotates the chosen basis onto the computational basis,
+    /// so a standard Z measurement performed afterwards reports the outcome
+    /// relative to this basis.
+    pub fn change_of_basis(&self) -> QuantumGate {
+        match self {
+            MeasurementBasis::Z => QuantumGate {
+                matrix: [
+                    [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+                    [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+                ],
+            },
+            MeasurementBasis::X => QuantumGate::hadamard(),
+            // H·S†, mapping the Y eigenstates |±i⟩ onto |0⟩ and |1⟩.
+            MeasurementBasis::Y => {
+                let factor = 1.0 / f64::consts::SQRT_2;
+                QuantumGate {
+                    matrix: [
+                        [Complex::new(factor, 0.0), Complex::new(0.0, -factor)],
+                        [Complex::new(factor, 0.0), Complex::new(0.0, factor)],
+                    ],
+                }
+            }
+            // Rows are the bra vectors ⟨v0| and ⟨v1| (conjugate transpose).
+            MeasurementBasis::Custom(v0, v1) => QuantumGate {
+                matrix: [
+                    [v0[0].conjugate(), v0[1].conjugate()],
+                    [v1[0].conjugate(), v1[1].conjugate()],
+                ],
+            },
+        }
+    }
+}
+
+/// A single-qubit gate promoted to a controlled operation: `gate` acts on
+/// `target` only for basis states where `control` is 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Controlled {
+    gate: QuantumGate,
+    control: usize,
+    target: usize,
+}
+
+impl Controlled {
+    pub fn new(gate: QuantumGate, control: usize, target: usize) -> Self {
+        Self { gate, control, target }
+    }
+
+    /// Controlled-NOT (controlled Pauli-X).
+    pub fn cnot(control: usize, target: usize) -> Self {
+        Self::new(QuantumGate::pauli_x(), control, target)
+    }
+
+    /// Controlled-Z.
+    pub fn cz(control: usize, target: usize) -> Self {
+        Self::new(QuantumGate::pauli_z(), control, target)
+    }
+
+    pub fn apply(&self, register: &mut QRegister) {
+        register.apply_controlled_gate(&self.gate, self.control, self.target);
+    }
+}
+
+/// A single step in a [`QuantumCircuit`]: a gate on `target`, optionally
+/// conditioned on `control`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    pub gate: QuantumGate,
+    pub target: usize,
+    pub control: Option<usize>,
+}
+
+/// An ordered program of gate applications that can be replayed over a register.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QuantumCircuit {
+    pub operations: Vec<Operation>,
+}
+
+impl QuantumCircuit {
+    pub fn new() -> Self {
+        Self { operations: Vec::new() }
+    }
+
+    /// Append a single-qubit gate acting on `target`.
+    pub fn add_gate(&mut self, gate: QuantumGate, target: usize) {
+        self.operations.push(Operation { gate, target, control: None });
+    }
+
+    /// Append a controlled gate conditioned on `control`.
+    pub fn add_controlled(&mut self, gate: QuantumGate, control: usize, target: usize) {
+        self.operations.push(Operation { gate, target, control: Some(control) });
+    }
+
+    /// Apply every operation to `register` in insertion order.
+    pub fn run(&self, register: &mut QRegister) {
+        for op in &self.operations {
+            match op.control {
+                Some(control) => register.apply_controlled_gate(&op.gate, control, op.target),
+                None => register.apply_gate(&op.gate, op.target),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qubit {
+    pub state: [Complex; 2],
+}
+
+impl Qubit {
+    pub fn new(alpha: Complex, beta: Complex) -> Self {
+        let mut qubit = Self { state: [alpha, beta] };
+        qubit.normalize();
+        qubit
+    }
+
+    fn normalize(&mut self) {
+        let norm = (self.state[0].modulus_squared() + self.state[1].modulus_squared()).sqrt();
+        self.state[0].real /= norm;
+        self.state[0].imag /= norm;
+        self.state[1].real /= norm;
+        self.state[1].imag /= norm;
+    }
+
+    pub fn measure(&mut self) -> usize {
+        let probability_0 = self.state[0].modulus_squared();
+        let r: f64 = rand::thread_rng().gen();
+        if r < probability_0 {
+            self.state = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+            0
+        } else {
+            self.state = [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+            1
+        }
+    }
+
+    /// Measure the qubit in an arbitrary basis. The state is first rotated so
+    /// the chosen basis aligns with the computational one, then sampled; the
+    /// returned outcome is relative to `basis`.
+    pub fn measure_in_basis(&mut self, basis: &MeasurementBasis) -> usize {
+        *self = basis.change_of_basis().apply(self);
+        self.measure()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumGate {
+    pub matrix: [[Complex; 2]; 2],
+}
+
+impl QuantumGate {
+    /// Pauli-X (bit flip), the quantum NOT gate.
+    pub fn pauli_x() -> QuantumGate {
+        QuantumGate {
+            matrix: [
+                [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+                [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+            ],
+        }
+    }
+
+    /// Pauli-Y, with the imaginary off-diagonal entries ∓i.
+    pub fn pauli_y() -> QuantumGate {
+        QuantumGate {
+            matrix: [
+                [Complex::new(0.0, 0.0), Complex::new(0.0, -1.0)],
+                [Complex::new(0.0, 1.0), Complex::new(0.0, 0.0)],
+            ],
+        }
+    }
+
+    /// Pauli-Z (phase flip).
+    pub fn pauli_z() -> QuantumGate {
+        QuantumGate {
+            matrix: [
+                [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+                [Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0)],
+            ],
+        }
+    }
+
+    /// Hadamard gate, placing a basis state into an equal superposition.
+    pub fn hadamard() -> QuantumGate {
+        let factor = 1.0 / f64::consts::SQRT_2;
+        QuantumGate {
+            matrix: [
+                [Complex::new(factor, 0.0), Complex::new(factor, 0.0)],
+                [Complex::new(factor, 0.0), Complex::new(-factor, 0.0)],
+            ],
+        }
+    }
+
+    /// `S` phase gate, a π/2 rotation about the Z axis.
+    pub fn s() -> QuantumGate {
+        QuantumGate::phase(f64::consts::FRAC_PI_2)
+    }
+
+    /// `T` phase gate, a π/4 rotation about the Z axis.
+    pub fn t() -> QuantumGate {
+        QuantumGate::phase(f64::consts::FRAC_PI_4)
+    }
+
+    /// General phase gate `[[1, 0], [0, e^{iθ}]]`.
+    pub fn phase(theta: f64) -> QuantumGate {
+        QuantumGate {
+            matrix: [
+                [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+                [Complex::new(0.0, 0.0), Complex::new(theta.cos(), theta.sin())],
+            ],
+        }
+    }
+
+    /// Rotation about the X axis by angle `theta`.
+    pub fn rx(theta: f64) -> QuantumGate {
+        let c = (theta / 2.0).cos();
+        let s = (theta / 2.0).sin();
+        QuantumGate {
+            matrix: [
+                [Complex::new(c, 0.0), Complex::new(0.0, -s)],
+                [Complex::new(0.0, -s), Complex::new(c, 0.0)],
+            ],
+        }
+    }
+
+    /// Rotation about the Y axis by angle `theta`.
+    pub fn ry(theta: f64) -> QuantumGate {
+        let c = (theta / 2.0).cos();
+        let s = (theta / 2.0).sin();
+        QuantumGate {
+            matrix: [
+                [Complex::new(c, 0.0), Complex::new(-s, 0.0)],
+                [Complex::new(s, 0.0), Complex::new(c, 0.0)],
+            ],
+        }
+    }
+
+    /// Rotation about the Z axis by angle `theta`.
+    pub fn rz(theta: f64) -> QuantumGate {
+        let c = (theta / 2.0).cos();
+        let s = (theta / 2.0).sin();
+        QuantumGate {
+            matrix: [
+                [Complex::new(c, -s), Complex::new(0.0, 0.0)],
+                [Complex::new(0.0, 0.0), Complex::new(c, s)],
+            ],
+        }
+    }
+
+    pub fn apply(&self, qubit: &Qubit) -> Qubit {
+        let new_state = [
+            self.matrix[0][0].mul(&qubit.state[0]).add(&self.matrix[0][1].mul(&qubit.state[1])),
+            self.matrix[1][0].mul(&qubit.state[0]).add(&self.matrix[1][1].mul(&qubit.state[1])),
+        ];
+        Qubit::new(new_state[0], new_state[1])
+    }
+}
+
+pub fn hadamard_gate() -> QuantumGate {
+    QuantumGate::hadamard()
+}
+
+/// Pretty-print a measurement histogram as `|bits⟩: count` lines, ordered by
+/// basis state, with each index rendered as an `num_qubits`-wide bit string.
+pub fn print_histogram(counts: &HashMap<usize, usize>, num_qubits: usize) {
+    let mut entries: Vec<(&usize, &usize)> = counts.iter().collect();
+    entries.sort_by_key(|(state, _)| **state);
+    for (state, count) in entries {
+        println!("|{:0width$b}⟩: {}", state, count, width = num_qubits);
+    }
+}
+
+/// Apply the Quantum Fourier Transform to the `qubits` of `register`, listed
+/// most-significant first. Each qubit gets a Hadamard followed by controlled
+/// phase rotations `R_k` of angle `2π/2^k` from the less-significant qubits,
+/// and the qubit order is reversed with swaps at the end.
+pub fn qft(register: &mut QRegister, qubits: &[usize]) {
+    let n = qubits.len();
+    for j in 0..n {
+        register.apply_gate(&QuantumGate::hadamard(), qubits[j]);
+        for k in (j + 1)..n {
+            let angle = 2.0 * f64::consts::PI / (1u64 << (k - j + 1)) as f64;
+            register.apply_controlled_gate(&QuantumGate::phase(angle), qubits[k], qubits[j]);
+        }
+    }
+    for i in 0..n / 2 {
+        register.swap(qubits[i], qubits[n - 1 - i]);
+    }
+}
+
+/// The inverse Quantum Fourier Transform: the forward operations replayed in
+/// reverse order with negated rotation angles.
+pub fn inverse_qft(register: &mut QRegister, qubits: &[usize]) {
+    let n = qubits.len();
+    for i in 0..n / 2 {
+        register.swap(qubits[i], qubits[n - 1 - i]);
+    }
+    for j in (0..n).rev() {
+        for k in ((j + 1)..n).rev() {
+            let angle = -2.0 * f64::consts::PI / (1u64 << (k - j + 1)) as f64;
+            register.apply_controlled_gate(&QuantumGate::phase(angle), qubits[k], qubits[j]);
+        }
+        register.apply_gate(&QuantumGate::hadamard(), qubits[j]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_operations() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, 4.0);
+        let sum = a.add(&b);
+        assert_eq!(sum, Complex::new(4.0, 6.0));
+
+        let product = a.mul(&b);
+        assert_eq!(product, Complex::new(-5.0, 10.0));
+    }
+
+    #[test]
+    fn test_qubit_initialization() {
+        let qubit = Qubit::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        assert_eq!(qubit.state[0], Complex::new(1.0, 0.0));
+        assert_eq!(qubit.state[1], Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_hadamard_gate() {
+        let qubit = Qubit::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        let hadamard = hadamard_gate();
+        let transformed = hadamard.apply(&qubit);
+        let expected_factor = 1.0 / f64::consts::SQRT_2;
+        assert!((transformed.state[0].real - expected_factor).abs() < 1e-10);
+        assert!((transformed.state[1].real - expected_factor).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_pauli_x_flips_basis_state() {
+        let qubit = Qubit::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        let flipped = QuantumGate::pauli_x().apply(&qubit);
+        assert_eq!(flipped.state[0], Complex::new(0.0, 0.0));
+        assert_eq!(flipped.state[1], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_pauli_y_has_imaginary_entries() {
+        let qubit = Qubit::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        let transformed = QuantumGate::pauli_y().apply(&qubit);
+        // Y|0⟩ = i|1⟩.
+        assert_eq!(transformed.state[0], Complex::new(0.0, 0.0));
+        assert_eq!(transformed.state[1], Complex::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_t_gate_applies_pi_over_four_phase() {
+        let one = Qubit::new(Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+        let transformed = QuantumGate::t().apply(&one);
+        let expected = Complex::new(f64::consts::FRAC_PI_4.cos(), f64::consts::FRAC_PI_4.sin());
+        assert!((transformed.state[1].real - expected.real).abs() < 1e-10);
+        assert!((transformed.state[1].imag - expected.imag).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cnot_wrapper_entangles() {
+        let mut register = QRegister::new(2);
+        register.apply_gate(&QuantumGate::hadamard(), 0);
+        Controlled::cnot(0, 1).apply(&mut register);
+        assert!((register.state[0].modulus_squared() - 0.5).abs() < 1e-10);
+        assert!((register.state[3].modulus_squared() - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_circuit_builds_bell_pair() {
+        let mut circuit = QuantumCircuit::new();
+        circuit.add_gate(QuantumGate::hadamard(), 0);
+        circuit.add_controlled(QuantumGate::pauli_x(), 0, 1);
+        let mut register = QRegister::new(2);
+        circuit.run(&mut register);
+        assert!((register.state[0].modulus_squared() - 0.5).abs() < 1e-10);
+        assert!((register.state[3].modulus_squared() - 0.5).abs() < 1e-10);
+        assert_eq!(register.state[1].modulus_squared(), 0.0);
+    }
+
+    #[test]
+    fn test_sample_is_non_destructive_and_counts_shots() {
+        let mut register = QRegister::new(2);
+        register.apply_gate(&QuantumGate::hadamard(), 0);
+        register.apply_controlled_gate(&QuantumGate::pauli_x(), 0, 1);
+        let before = register.clone();
+        let counts = register.sample(1000);
+        // Sampling must not disturb the amplitudes.
+        assert_eq!(register, before);
+        assert_eq!(counts.values().sum::<usize>(), 1000);
+        // A Bell pair only ever yields |00⟩ or |11⟩.
+        assert!(counts.keys().all(|state| *state == 0 || *state == 3));
+    }
+
+    #[test]
+    fn test_x_basis_measurement_is_deterministic_for_plus_state() {
+        // |+⟩ = H|0⟩ is the +1 eigenstate of X, so an X-basis measurement
+        // always reports outcome 0 — something a Z measurement cannot see.
+        let plus = QuantumGate::hadamard().apply(&Qubit::new(
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, 0.0),
+        ));
+        for _ in 0..50 {
+            let mut qubit = plus.clone();
+            assert_eq!(qubit.measure_in_basis(&MeasurementBasis::X), 0);
+        }
+    }
+
+    #[test]
+    fn test_custom_basis_matches_z_when_given_computational_vectors() {
+        let basis = MeasurementBasis::Custom(
+            [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+        );
+        let mut qubit = Qubit::new(Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+        assert_eq!(qubit.measure_in_basis(&basis), 1);
+    }
+
+    #[test]
+    fn test_qft_of_zero_state_is_uniform_superposition() {
+        let mut register = QRegister::new(3);
+        qft(&mut register, &[0, 1, 2]);
+        // QFT|000⟩ spreads amplitude equally over all 8 basis states.
+        let expected = 1.0 / 8.0;
+        for amp in &register.state {
+            assert!((amp.modulus_squared() - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_inverse_qft_restores_state() {
+        let mut register = QRegister::new(3);
+        register.apply_gate(&QuantumGate::pauli_x(), 1);
+        let original = register.clone();
+        qft(&mut register, &[0, 1, 2]);
+        inverse_qft(&mut register, &[0, 1, 2]);
+        for (got, want) in register.state.iter().zip(original.state.iter()) {
+            assert!((got.real - want.real).abs() < 1e-10);
+            assert!((got.imag - want.imag).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_register_initialization() {
+        let register = QRegister::new(3);
+        assert_eq!(register.state.len(), 8);
+        assert_eq!(register.state[0], Complex::new(1.0, 0.0));
+        assert!(register.state[1..].iter().all(|amp| *amp == Complex::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_register_from_qubits_is_kronecker_product() {
+        let zero = Qubit::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        let one = Qubit::new(Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+        let register = QRegister::from_qubits(&[zero, one]);
+        // |01⟩ is basis index 1.
+        assert_eq!(register.state[1], Complex::new(1.0, 0.0));
+        assert_eq!(register.state[0].modulus_squared(), 0.0);
+    }
+
+    #[test]
+    fn test_bell_pair_via_hadamard_and_cnot() {
+        let pauli_x = QuantumGate {
+            matrix: [
+                [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+                [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+            ],
+        };
+        let mut register = QRegister::new(2);
+        register.apply_gate(&hadamard_gate(), 0);
+        register.apply_controlled_gate(&pauli_x, 0, 1);
+        // (|00⟩ + |11⟩)/√2: only indices 0 and 3 are populated, equally.
+        let half = 0.5;
+        assert!((register.state[0].modulus_squared() - half).abs() < 1e-10);
+        assert!((register.state[3].modulus_squared() - half).abs() < 1e-10);
+        assert_eq!(register.state[1].modulus_squared(), 0.0);
+        assert_eq!(register.state[2].modulus_squared(), 0.0);
+    }
+}